@@ -6,7 +6,9 @@ extern crate test;
 
 use std::ops::{ Index, IndexMut };
 use std::collections::BTreeMap;
-use std::cmp::min;
+use std::cmp::{ min, max };
+use std::fmt;
+use std::error::Error;
 
 // A simple wrapper around vec so we can get contiguous but index it like it's 2D array.
 struct N2Array<T> {
@@ -36,70 +38,154 @@ impl<T> IndexMut<(usize, usize)> for N2Array<T> {
     }
 }
 
-/// Calculate the distance between two strings using the levenshtein algorithm.
-/// 
-/// > Levenshtein distance is a string metric for measuring the difference between two sequences. 
-/// > Informally, the Levenshtein distance between two words is the minimum number of single-character edits 
-/// > (i.e. insertions, deletions or substitutions) required to change one word into the other.
-/// [wikipedia](http://en.wikipedia.org/wiki/Levenshtein_distance)
-/// 
+/// Errors that can occur while calculating a distance.
+#[derive(Debug, PartialEq, Eq)]
+pub enum DistError {
+    /// The two inputs did not have the same number of characters, so a
+    /// position-by-position comparison could not be made.
+    DifferentLengths,
+}
+
+impl fmt::Display for DistError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            DistError::DifferentLengths => write!(f, "inputs have a different number of characters"),
+        }
+    }
+}
+
+impl Error for DistError {}
+
+/// Calculate the Hamming distance between two strings, i.e. the number of
+/// positions at which the corresponding characters differ.
+///
+/// > In information theory, the Hamming distance between two strings of equal
+/// > length is the number of positions at which the corresponding symbols are
+/// > different.
+/// [wikipedia](http://en.wikipedia.org/wiki/Hamming_distance)
+///
+/// Since the metric is only defined for equal-length inputs, `source` and
+/// `target` having a different number of characters is reported as an error
+/// rather than silently truncated to the shorter length.
+///
 /// # Example
 ///
 /// ```rust
-/// use txtdist::levenshtein;
+/// use txtdist::hamming;
 ///
-/// let distance = levenshtein("an act", "a cat");
-/// assert_eq!(distance, 3)
+/// let distance = hamming("karolin", "kathrin");
+/// assert_eq!(distance, Ok(3))
 /// ```
-pub fn levenshtein(source: &str, target: &str) -> u32 {
+pub fn hamming(source: &str, target: &str) -> Result<u32, DistError> {
+    let source: Vec<char> = source.chars().collect();
+    let target: Vec<char> = target.chars().collect();
+
+    if source.len() != target.len() {
+        return Err(DistError::DifferentLengths);
+    }
+
+    let distance = source.iter()
+        .zip(target.iter())
+        .filter(|(a, b)| a != b)
+        .count();
+
+    Ok(distance as u32)
+}
+
+/// Calculate the levenshtein distance between two arbitrary sequences of comparable
+/// elements, e.g. byte slices, word slices, or any other `PartialEq`-comparable items.
+///
+/// `levenshtein` is a thin wrapper around this function that operates over `char`s; use
+/// this directly when the input isn't naturally a `&str`, such as `&[u8]` for byte
+/// sequences or `&[&str]` for word-level diffing.
+///
+/// Unlike `generic_damerau_levenshtein`, this only ever needs the previous and current
+/// row of the edit matrix, so it's computed with two rolling rows of `O(min(n, m))`
+/// memory rather than the full `(n+1)×(m+1)` matrix.
+pub fn generic_levenshtein<I, J, A, B>(source: I, target: J) -> u32
+    where I: IntoIterator<Item = A>,
+          J: IntoIterator<Item = B>,
+          A: PartialEq<B>
+{
+    let source: Vec<A> = source.into_iter().collect();
+    let target: Vec<B> = target.into_iter().collect();
     let (n, m) = (source.len(), target.len());
 
-    let mut matrix = N2Array::new(n+1, m+1, 0);
+    // Keep the rolling rows as short as possible by iterating the longer sequence and
+    // rolling over the shorter one, regardless of which side it is.
+    if n < m {
+        let mut prev_row: Vec<usize> = (0..=n).collect();
+        let mut curr_row = vec![0; n + 1];
+
+        for (col, elem_t) in target.iter().enumerate() {
+            curr_row[0] = col + 1;
+
+            for (row, elem_s) in source.iter().enumerate() {
+                curr_row[row + 1] = if elem_s.eq(elem_t) {
+                    prev_row[row]
+                } else {
+                    min(prev_row[row] + 1, min(prev_row[row + 1] + 1, curr_row[row] + 1))
+                };
+            }
 
-    for i in 1..n+1 {
-        matrix[(i, 0)] = i;
-    }
+            std::mem::swap(&mut prev_row, &mut curr_row);
+        }
 
-    for i in 1..m+1 {
-        matrix[(0, i)] = i;
-    }
+        prev_row[n] as u32
+    } else {
+        let mut prev_row: Vec<usize> = (0..=m).collect();
+        let mut curr_row = vec![0; m + 1];
 
-    for (row, char_s) in source.chars().enumerate() {
-        let row = row + 1;
-        
-        for (col, char_t) in target.chars().enumerate() {
-            let col = col + 1;
-            if char_s == char_t {
-                matrix[(row, col)] = matrix[(row-1, col-1)];
-            } else {
-                matrix[(row, col)] = min(matrix[(row-1, col)]   + 1, 
-                                     min(matrix[(row, col-1)]   + 1,
-                                         matrix[(row-1, col-1)] + 1));
+        for (row, elem_s) in source.iter().enumerate() {
+            curr_row[0] = row + 1;
+
+            for (col, elem_t) in target.iter().enumerate() {
+                curr_row[col + 1] = if elem_s.eq(elem_t) {
+                    prev_row[col]
+                } else {
+                    min(prev_row[col] + 1, min(prev_row[col + 1] + 1, curr_row[col] + 1))
+                };
             }
+
+            std::mem::swap(&mut prev_row, &mut curr_row);
         }
-    }
 
-    matrix[(n, m)] as u32
+        prev_row[m] as u32
+    }
 }
 
-/// Calculate the distance between two strings using the damerau levenshtein algorithm. 
-/// 
-/// > The Damerau–Levenshtein distance (named after Frederick J. Damerau and Vladimir I. Levenshtein) 
-/// > is a distance (string metric) between two strings, i.e., finite sequence of symbols, 
-/// > given by counting the minimum number of operations needed to transform one string into the other, 
-/// > where an operation is defined as an insertion, deletion, or substitution of a single character, 
-/// > or a transposition of two adjacent characters. 
-/// [wikipedia](http://en.wikipedia.org/wiki/Damerau%E2%80%93Levenshtein_distance)
-/// 
+/// Calculate the distance between two strings using the levenshtein algorithm.
+///
+/// > Levenshtein distance is a string metric for measuring the difference between two sequences.
+/// > Informally, the Levenshtein distance between two words is the minimum number of single-character edits
+/// > (i.e. insertions, deletions or substitutions) required to change one word into the other.
+/// [wikipedia](http://en.wikipedia.org/wiki/Levenshtein_distance)
+///
 /// # Example
 ///
 /// ```rust
-/// use txtdist::damerau_levenshtein;
+/// use txtdist::levenshtein;
 ///
-/// let distance = damerau_levenshtein("an act", "a cat");
-/// assert_eq!(distance, 2)
+/// let distance = levenshtein("an act", "a cat");
+/// assert_eq!(distance, 3)
 /// ```
-pub fn damerau_levenshtein(source: &str, target: &str) -> u32 {
+pub fn levenshtein(source: &str, target: &str) -> u32 {
+    generic_levenshtein(source.chars(), target.chars())
+}
+
+/// Calculate the damerau levenshtein distance between two arbitrary sequences of
+/// comparable elements.
+///
+/// Unlike `generic_levenshtein`, tracking transpositions requires both sequences to
+/// share the same element type `T: PartialEq + Ord + Clone`, since the algorithm keeps
+/// a map from an element to the last row it was seen in.
+pub fn generic_damerau_levenshtein<I, J, T>(source: I, target: J) -> u32
+    where I: IntoIterator<Item = T>,
+          J: IntoIterator<Item = T>,
+          T: PartialEq + Ord + Clone
+{
+    let source: Vec<T> = source.into_iter().collect();
+    let target: Vec<T> = target.into_iter().collect();
     let (n, m) = (source.len(), target.len());
 
     if n == 0 { return m as u32; }
@@ -108,7 +194,7 @@ pub fn damerau_levenshtein(source: &str, target: &str) -> u32 {
     if n == m && source == target {
         return 0;
     }
-        
+
     let inf = n + m;
     let mut matrix = N2Array::new(n+2, m+2, 0);
 
@@ -122,40 +208,348 @@ pub fn damerau_levenshtein(source: &str, target: &str) -> u32 {
         matrix[(1, j+1)] = j;
     };
 
-    let mut last_row = BTreeMap::new();
+    let mut last_row: BTreeMap<T, usize> = BTreeMap::new();
 
-    for (row, char_s) in source.chars().enumerate() {
+    for (row, elem_s) in source.iter().enumerate() {
         let mut last_match_col = 0;
         let row = row + 1;
-        
-        for (col, char_t) in target.chars().enumerate() {
+
+        for (col, elem_t) in target.iter().enumerate() {
             let col = col + 1;
-            let last_match_row = *last_row.get(&char_t).unwrap_or(&0);
-            let cost = if char_s == char_t { 0 } else { 1 };
+            let last_match_row = *last_row.get(elem_t).unwrap_or(&0);
+            let cost = if elem_s == elem_t { 0 } else { 1 };
 
             let dist_add = matrix[(row, col+1)] + 1;
             let dist_del = matrix[(row+1, col)] + 1;
-            let dist_sub = matrix[(row, col)] + cost; 
+            let dist_sub = matrix[(row, col)] + cost;
             let dist_trans = matrix[(last_match_row, last_match_col)]
                             + (row - last_match_row - 1) + 1
                             + (col - last_match_col - 1);
 
-            let dist = min(min(dist_add, dist_del), 
+            let dist = min(min(dist_add, dist_del),
                            min(dist_sub, dist_trans));
 
             matrix[(row+1, col+1)] = dist;
-            
+
             if cost == 0 {
                 last_match_col = col;
             }
         }
 
-        last_row.insert(char_s.clone(), row);
+        last_row.insert(elem_s.clone(), row);
     }
 
     matrix[(n+1, m+1)] as u32
 }
 
+/// Calculate the distance between two strings using the damerau levenshtein algorithm.
+///
+/// > The Damerau–Levenshtein distance (named after Frederick J. Damerau and Vladimir I. Levenshtein)
+/// > is a distance (string metric) between two strings, i.e., finite sequence of symbols,
+/// > given by counting the minimum number of operations needed to transform one string into the other,
+/// > where an operation is defined as an insertion, deletion, or substitution of a single character,
+/// > or a transposition of two adjacent characters.
+/// [wikipedia](http://en.wikipedia.org/wiki/Damerau%E2%80%93Levenshtein_distance)
+///
+/// # Example
+///
+/// ```rust
+/// use txtdist::damerau_levenshtein;
+///
+/// let distance = damerau_levenshtein("an act", "a cat");
+/// assert_eq!(distance, 2)
+/// ```
+pub fn damerau_levenshtein(source: &str, target: &str) -> u32 {
+    generic_damerau_levenshtein(source.chars(), target.chars())
+}
+
+/// Calculate the optimal string alignment (restricted edit) distance between two
+/// arbitrary sequences of comparable elements.
+///
+/// `damerau_levenshtein` allows a character to take part in an unbounded number of
+/// transpositions; OSA is the cheaper, more commonly expected variant where each
+/// substring may only be edited once, so `"ca"` can become `"ac"` via one transposition,
+/// but not be transposed again afterwards.
+pub fn generic_osa_distance<I, J, A, B>(source: I, target: J) -> u32
+    where I: IntoIterator<Item = A>,
+          J: IntoIterator<Item = B>,
+          A: PartialEq<B>
+{
+    let source: Vec<A> = source.into_iter().collect();
+    let target: Vec<B> = target.into_iter().collect();
+    let (n, m) = (source.len(), target.len());
+
+    let mut matrix = N2Array::new(n+1, m+1, 0);
+
+    for i in 1..n+1 {
+        matrix[(i, 0)] = i;
+    }
+
+    for i in 1..m+1 {
+        matrix[(0, i)] = i;
+    }
+
+    for row in 1..n+1 {
+        for col in 1..m+1 {
+            let cost = if source[row-1].eq(&target[col-1]) { 0 } else { 1 };
+
+            let mut dist = min(matrix[(row-1, col)]   + 1,
+                           min(matrix[(row, col-1)]   + 1,
+                               matrix[(row-1, col-1)] + cost));
+
+            if row > 1 && col > 1
+                && source[row-1].eq(&target[col-2])
+                && source[row-2].eq(&target[col-1])
+            {
+                dist = min(dist, matrix[(row-2, col-2)] + 1);
+            }
+
+            matrix[(row, col)] = dist;
+        }
+    }
+
+    matrix[(n, m)] as u32
+}
+
+/// Calculate the distance between two strings using optimal string alignment (OSA),
+/// a.k.a. the restricted edit distance.
+///
+/// > ... the OSA algorithm computes the number of edit operations needed to make the
+/// > strings equal under the condition that no substring is edited more than once.
+/// [wikipedia](http://en.wikipedia.org/wiki/Damerau%E2%80%93Levenshtein_distance#Optimal_string_alignment_distance)
+///
+/// # Example
+///
+/// ```rust
+/// use txtdist::osa_distance;
+///
+/// let distance = osa_distance("CA", "ABC");
+/// assert_eq!(distance, 3)
+/// ```
+pub fn osa_distance(source: &str, target: &str) -> u32 {
+    generic_osa_distance(source.chars(), target.chars())
+}
+
+/// Calculate the normalized levenshtein similarity between two strings, a score between
+/// `0.0` and `1.0` where `1.0` means the strings are identical.
+///
+/// This is `1.0 - levenshtein(source, target) / max(len_source, len_target)`, which lets
+/// callers threshold fuzzy matches without reimplementing the length bookkeeping
+/// themselves. Two empty strings are defined to be identical, and score `1.0`.
+///
+/// # Example
+///
+/// ```rust
+/// use txtdist::normalized_levenshtein;
+///
+/// let similarity = normalized_levenshtein("an act", "a cat");
+/// assert_eq!(similarity, 0.5)
+/// ```
+pub fn normalized_levenshtein(source: &str, target: &str) -> f64 {
+    let (len_source, len_target) = (source.chars().count(), target.chars().count());
+
+    if len_source == 0 && len_target == 0 {
+        return 1.0;
+    }
+
+    let distance = levenshtein(source, target) as f64;
+
+    1.0 - distance / max(len_source, len_target) as f64
+}
+
+/// Calculate the normalized damerau-levenshtein similarity between two strings, a score
+/// between `0.0` and `1.0` where `1.0` means the strings are identical.
+///
+/// See `normalized_levenshtein` for the normalization used.
+///
+/// # Example
+///
+/// ```rust
+/// use txtdist::normalized_damerau_levenshtein;
+///
+/// let similarity = normalized_damerau_levenshtein("an act", "a cat");
+/// assert!((similarity - 0.6666666666666667).abs() < 1e-9)
+/// ```
+pub fn normalized_damerau_levenshtein(source: &str, target: &str) -> f64 {
+    let (len_source, len_target) = (source.chars().count(), target.chars().count());
+
+    if len_source == 0 && len_target == 0 {
+        return 1.0;
+    }
+
+    let distance = damerau_levenshtein(source, target) as f64;
+
+    1.0 - distance / max(len_source, len_target) as f64
+}
+
+/// Calculate the Jaro similarity between two strings, a score between `0.0` and `1.0`
+/// where `1.0` means the strings are identical.
+///
+/// > The Jaro similarity is a measure of similarity between two strings; the higher
+/// > the Jaro distance for two strings is, the more similar the strings are. The score
+/// > is normalized such that `0` equates to no similarity and `1` is an exact match.
+/// [wikipedia](http://en.wikipedia.org/wiki/Jaro%E2%80%93Winkler_distance)
+///
+/// Unlike the edit-distance metrics above, Jaro is token-insensitive and tends to work
+/// better on short strings such as names.
+///
+/// # Example
+///
+/// ```rust
+/// use txtdist::jaro;
+///
+/// let similarity = jaro("MARTHA", "MARHTA");
+/// assert!((similarity - 0.9444444444444445).abs() < 1e-9)
+/// ```
+pub fn jaro(source: &str, target: &str) -> f64 {
+    let source: Vec<char> = source.chars().collect();
+    let target: Vec<char> = target.chars().collect();
+    let (len1, len2) = (source.len(), target.len());
+
+    if len1 == 0 && len2 == 0 {
+        return 1.0;
+    }
+
+    if len1 == 0 || len2 == 0 {
+        return 0.0;
+    }
+
+    let match_window = (max(len1, len2) / 2).saturating_sub(1);
+
+    let mut source_matched = vec![false; len1];
+    let mut target_matched = vec![false; len2];
+    let mut m = 0;
+
+    for i in 0..len1 {
+        let start = i.saturating_sub(match_window);
+        let end = min(i + match_window + 1, len2);
+
+        for j in start..end {
+            if target_matched[j] || source[i] != target[j] {
+                continue;
+            }
+
+            source_matched[i] = true;
+            target_matched[j] = true;
+            m += 1;
+            break;
+        }
+    }
+
+    if m == 0 {
+        return 0.0;
+    }
+
+    let mut t = 0;
+    let mut k = 0;
+
+    for i in 0..len1 {
+        if !source_matched[i] {
+            continue;
+        }
+
+        while !target_matched[k] {
+            k += 1;
+        }
+
+        if source[i] != target[k] {
+            t += 1;
+        }
+
+        k += 1;
+    }
+
+    let t = (t / 2) as f64;
+    let m = m as f64;
+
+    (m / len1 as f64 + m / len2 as f64 + (m - t) / m) / 3.0
+}
+
+/// Calculate the Jaro-Winkler similarity between two strings, a score between `0.0` and
+/// `1.0` that boosts the Jaro similarity for strings that share a common prefix.
+///
+/// > Jaro–Winkler distance uses a prefix scale `p` which gives more favourable ratings
+/// > to strings that match from the beginning for a set prefix length.
+/// [wikipedia](http://en.wikipedia.org/wiki/Jaro%E2%80%93Winkler_distance)
+///
+/// # Example
+///
+/// ```rust
+/// use txtdist::jaro_winkler;
+///
+/// let similarity = jaro_winkler("MARTHA", "MARHTA");
+/// assert!((similarity - 0.9611111111111111).abs() < 1e-9)
+/// ```
+pub fn jaro_winkler(source: &str, target: &str) -> f64 {
+    let jaro = jaro(source, target);
+
+    let prefix_len = source
+        .chars()
+        .zip(target.chars())
+        .take_while(|(a, b)| a == b)
+        .take(4)
+        .count();
+
+    let p = 0.1;
+
+    jaro + (prefix_len as f64) * p * (1.0 - jaro)
+}
+
+/// Calculate the Sørensen–Dice similarity between two strings, a score between `0.0` and
+/// `1.0` based on the overlap of adjacent character bigrams.
+///
+/// > The Sørensen–Dice coefficient ... is a statistic used to gauge the similarity of two
+/// > samples. ... Its similarity coefficient is twice the number of elements common to
+/// > both sets divided by the sum of the number of elements in each set.
+/// [wikipedia](http://en.wikipedia.org/wiki/S%C3%B8rensen%E2%80%93Dice_coefficient)
+///
+/// Bigram multiplicity is respected, so repeated bigrams in one string can only match
+/// as many occurrences as exist in the other. Unlike the edit-distance metrics, this is
+/// tolerant of transposed words in multi-word inputs.
+///
+/// # Example
+///
+/// ```rust
+/// use txtdist::sorensen_dice;
+///
+/// let similarity = sorensen_dice("night", "nacht");
+/// assert_eq!(similarity, 0.25)
+/// ```
+pub fn sorensen_dice(source: &str, target: &str) -> f64 {
+    fn bigrams(s: &str) -> Vec<(char, char)> {
+        let chars: Vec<char> = s.chars().collect();
+        chars.windows(2).map(|w| (w[0], w[1])).collect()
+    }
+
+    if source == target {
+        return 1.0;
+    }
+
+    let source_bigrams = bigrams(source);
+    let target_bigrams = bigrams(target);
+
+    if source_bigrams.is_empty() || target_bigrams.is_empty() {
+        return 0.0;
+    }
+
+    let mut counts: BTreeMap<(char, char), u32> = BTreeMap::new();
+    for bigram in &source_bigrams {
+        *counts.entry(*bigram).or_insert(0) += 1;
+    }
+
+    let mut shared = 0;
+    for bigram in &target_bigrams {
+        if let Some(count) = counts.get_mut(bigram) {
+            if *count > 0 {
+                *count -= 1;
+                shared += 1;
+            }
+        }
+    }
+
+    2.0 * shared as f64 / (source_bigrams.len() + target_bigrams.len()) as f64
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -179,6 +573,17 @@ mod tests {
         assert_eq!(distance, 0);
     }
 
+    #[test]
+    fn test_levenshtein_unicode() {
+        // Regression test: the rolling rows must be sized and read back by char count,
+        // not byte count, or multi-byte input produces a bogus result.
+        let distance = levenshtein("café", "cafe");
+        assert_eq!(distance, 1);
+
+        let distance = levenshtein("こんにちは", "こんばんは");
+        assert_eq!(distance, 2);
+    }
+
     #[test]
     fn test_damerau_levenschtein() {
         let distance = damerau_levenshtein("CA", "ABC");
@@ -200,6 +605,142 @@ mod tests {
         assert_eq!(distance, 0);
     }
 
+    #[test]
+    fn test_damerau_levenshtein_unicode() {
+        // Regression test: the matrix must be sized and read back by char count,
+        // not byte count, or multi-byte input produces a bogus result.
+        let distance = damerau_levenshtein("café", "cafe");
+        assert_eq!(distance, 1);
+
+        let distance = damerau_levenshtein("José", "Jsoé");
+        assert_eq!(distance, 1);
+    }
+
+    #[test]
+    fn test_osa_distance() {
+        let distance = osa_distance("CA", "ABC");
+        assert_eq!(distance, 3);
+
+        let distance = osa_distance("ca", "ac");
+        assert_eq!(distance, 1);
+
+        let distance = osa_distance("", "");
+        assert_eq!(distance, 0);
+
+        let distance = osa_distance("string", "string");
+        assert_eq!(distance, 0);
+    }
+
+    #[test]
+    fn test_normalized_levenshtein() {
+        let similarity = normalized_levenshtein("an act", "a cat");
+        assert_eq!(similarity, 0.5);
+
+        let similarity = normalized_levenshtein("", "");
+        assert_eq!(similarity, 1.0);
+
+        let similarity = normalized_levenshtein("string", "string");
+        assert_eq!(similarity, 1.0);
+    }
+
+    #[test]
+    fn test_normalized_damerau_levenshtein() {
+        let similarity = normalized_damerau_levenshtein("an act", "a cat");
+        assert!((similarity - 0.6666666666666667).abs() < 1e-9);
+
+        let similarity = normalized_damerau_levenshtein("", "");
+        assert_eq!(similarity, 1.0);
+    }
+
+    #[test]
+    fn test_sorensen_dice() {
+        let similarity = sorensen_dice("night", "nacht");
+        assert_eq!(similarity, 0.25);
+
+        let similarity = sorensen_dice("", "");
+        assert_eq!(similarity, 1.0);
+
+        let similarity = sorensen_dice("", "abc");
+        assert_eq!(similarity, 0.0);
+
+        // Inputs shorter than a bigram (no shared bigram sets possible) must not be
+        // reported as a perfect match unless the strings are actually identical.
+        let similarity = sorensen_dice("a", "b");
+        assert_eq!(similarity, 0.0);
+
+        let similarity = sorensen_dice("a", "a");
+        assert_eq!(similarity, 1.0);
+
+        let similarity = sorensen_dice("context", "context");
+        assert_eq!(similarity, 1.0);
+
+        // multiplicity: "aa" has a single bigram "aa" repeated, "aaa" has two.
+        let similarity = sorensen_dice("aa", "aaa");
+        assert_eq!(similarity, 2.0 * 1.0 / (1.0 + 2.0));
+    }
+
+    #[test]
+    fn test_generic_levenshtein() {
+        let distance = generic_levenshtein("kitten".bytes(), "sitting".bytes());
+        assert_eq!(distance, 3);
+
+        let source = vec!["the", "quick", "fox"];
+        let target = vec!["the", "slow", "fox"];
+        let distance = generic_levenshtein(source, target);
+        assert_eq!(distance, 1);
+    }
+
+    #[test]
+    fn test_generic_damerau_levenshtein() {
+        let distance = generic_damerau_levenshtein("CA".bytes(), "ABC".bytes());
+        assert_eq!(distance, 2);
+    }
+
+    #[test]
+    fn test_hamming() {
+        let distance = hamming("karolin", "kathrin");
+        assert_eq!(distance, Ok(3));
+
+        let distance = hamming("karolin", "kerstin");
+        assert_eq!(distance, Ok(3));
+
+        let distance = hamming("", "");
+        assert_eq!(distance, Ok(0));
+
+        let distance = hamming("abc", "abcd");
+        assert_eq!(distance, Err(DistError::DifferentLengths));
+    }
+
+    #[test]
+    fn test_jaro() {
+        let similarity = jaro("MARTHA", "MARHTA");
+        assert!((similarity - 0.9444444444444445).abs() < 1e-9);
+
+        let similarity = jaro("DIXON", "DICKSONX");
+        assert!((similarity - 0.7666666666666666).abs() < 1e-9);
+
+        let similarity = jaro("", "");
+        assert_eq!(similarity, 1.0);
+
+        let similarity = jaro("", "jaro");
+        assert_eq!(similarity, 0.0);
+
+        let similarity = jaro("jaro", "jaro");
+        assert_eq!(similarity, 1.0);
+    }
+
+    #[test]
+    fn test_jaro_winkler() {
+        let similarity = jaro_winkler("MARTHA", "MARHTA");
+        assert!((similarity - 0.9611111111111111).abs() < 1e-9);
+
+        let similarity = jaro_winkler("DIXON", "DICKSONX");
+        assert!((similarity - 0.8133333333333332).abs() < 1e-9);
+
+        let similarity = jaro_winkler("", "");
+        assert_eq!(similarity, 1.0);
+    }
+
     #[bench]
     fn bench_damerau_levenschtein(b: &mut Bencher) {
         b.iter(|| damerau_levenshtein("one string", "other string"));